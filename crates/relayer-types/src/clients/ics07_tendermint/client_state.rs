@@ -20,6 +20,7 @@ use crate::core::ics02_client::error::Error as Ics02Error;
 use crate::core::ics02_client::trust_threshold::TrustThreshold;
 use crate::core::ics23_commitment::specs::ProofSpecs;
 use crate::core::ics24_host::identifier::ChainId;
+use crate::core::ics24_host::SDK_UPGRADE_PATH;
 use crate::timestamp::{Timestamp, ZERO_DURATION};
 use crate::Height;
 
@@ -77,6 +78,15 @@ impl ClientState {
             )));
         }
 
+        // A zero drift rejects any header from slightly ahead of the host clock.
+        // Like ibc-go, no upper bound is enforced: the relayer derives the drift
+        // from the chain configurations, and it may exceed short trusting periods.
+        if max_clock_drift <= Duration::new(0, 0) {
+            return Err(Error::invalid_max_clock_drift(format!(
+                "ClientState max clock drift ({max_clock_drift:?}) must be greater than zero"
+            )));
+        }
+
         // `TrustThreshold` is guaranteed to be in the range `[0, 1)`,
         // but a zero value is invalid in this context.
         if trust_threshold.numerator() == 0 {
@@ -94,6 +104,16 @@ impl ClientState {
             ));
         }
 
+        // The light client security model requires the trust threshold
+        // to be in the range `[1/3, 1)`.
+        if trust_threshold < TrustThreshold::ONE_THIRD
+            || trust_threshold.numerator() >= trust_threshold.denominator()
+        {
+            return Err(Error::invalid_trust_threshold(format!(
+                "ClientState trust threshold ({trust_threshold}) must be greater than or equal to 1/3 and less than 1"
+            )));
+        }
+
         // Disallow empty proof-specs
         if proof_specs.is_empty() {
             return Err(Error::validation(
@@ -186,6 +206,105 @@ impl ClientState {
             _ => Ok(()),
         }
     }
+
+    /// Returns a [`ClientStateBuilder`] for the given chain and latest height,
+    /// with every other parameter set to a default.
+    pub fn builder(chain_id: ChainId, latest_height: Height) -> ClientStateBuilder {
+        ClientStateBuilder::new(chain_id, latest_height)
+    }
+}
+
+/// Builder for a Tendermint [`ClientState`].
+///
+/// Unless overridden, the builder uses a 2/3 trust threshold, a 21 days
+/// unbonding period, a trusting period of 2/3 of the unbonding period,
+/// a 5 seconds maximum clock drift, the default proof specs and the
+/// standard Cosmos SDK upgrade path, and allows updates after expiry and
+/// misbehaviour.
+#[derive(Clone, Debug)]
+pub struct ClientStateBuilder {
+    chain_id: ChainId,
+    latest_height: Height,
+    trust_threshold: TrustThreshold,
+    trusting_period: Option<Duration>,
+    unbonding_period: Duration,
+    max_clock_drift: Duration,
+    proof_specs: ProofSpecs,
+    upgrade_path: Vec<String>,
+    allow_update: AllowUpdate,
+}
+
+impl ClientStateBuilder {
+    pub fn new(chain_id: ChainId, latest_height: Height) -> Self {
+        Self {
+            chain_id,
+            latest_height,
+            trust_threshold: TrustThreshold::default(),
+            trusting_period: None,
+            unbonding_period: Duration::from_secs(21 * 24 * 3600),
+            max_clock_drift: Duration::from_secs(5),
+            proof_specs: ProofSpecs::default(),
+            upgrade_path: SDK_UPGRADE_PATH.map(String::from).into(),
+            allow_update: AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        }
+    }
+
+    pub fn with_trust_threshold(mut self, trust_threshold: TrustThreshold) -> Self {
+        self.trust_threshold = trust_threshold;
+        self
+    }
+
+    pub fn with_trusting_period(mut self, trusting_period: Duration) -> Self {
+        self.trusting_period = Some(trusting_period);
+        self
+    }
+
+    pub fn with_unbonding_period(mut self, unbonding_period: Duration) -> Self {
+        self.unbonding_period = unbonding_period;
+        self
+    }
+
+    pub fn with_max_clock_drift(mut self, max_clock_drift: Duration) -> Self {
+        self.max_clock_drift = max_clock_drift;
+        self
+    }
+
+    pub fn with_proof_specs(mut self, proof_specs: ProofSpecs) -> Self {
+        self.proof_specs = proof_specs;
+        self
+    }
+
+    pub fn with_upgrade_path(mut self, upgrade_path: Vec<String>) -> Self {
+        self.upgrade_path = upgrade_path;
+        self
+    }
+
+    pub fn with_allow_update(mut self, allow_update: AllowUpdate) -> Self {
+        self.allow_update = allow_update;
+        self
+    }
+
+    /// Builds the client state, performing the same validation as [`ClientState::new`].
+    pub fn build(self) -> Result<ClientState, Error> {
+        let trusting_period = self
+            .trusting_period
+            .unwrap_or(2 * self.unbonding_period / 3);
+
+        ClientState::new(
+            self.chain_id,
+            self.trust_threshold,
+            trusting_period,
+            self.unbonding_period,
+            self.max_clock_drift,
+            self.latest_height,
+            self.proof_specs,
+            self.upgrade_path,
+            self.allow_update,
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -458,6 +577,22 @@ mod tests {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Invalid (zero) max clock drift".to_string(),
+                params: ClientStateParams {
+                    max_clock_drift: ZERO_DURATION,
+                    ..default_params.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Valid max clock drift as large as the trusting period".to_string(),
+                params: ClientStateParams {
+                    max_clock_drift: Duration::new(64000, 0),
+                    ..default_params.clone()
+                },
+                want_pass: true,
+            },
             Test {
                 name: "Invalid (zero) trust threshold".to_string(),
                 params: ClientStateParams {
@@ -466,6 +601,38 @@ mod tests {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Valid (1/3) trust threshold".to_string(),
+                params: ClientStateParams {
+                    trust_threshold: TrustThreshold::ONE_THIRD,
+                    ..default_params.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Valid (2/3) trust threshold".to_string(),
+                params: ClientStateParams {
+                    trust_threshold: TrustThreshold::TWO_THIRDS,
+                    ..default_params.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Invalid (too small) trust threshold".to_string(),
+                params: ClientStateParams {
+                    trust_threshold: TrustThreshold::new(1, 4).unwrap(),
+                    ..default_params.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Invalid (too large) trust threshold".to_string(),
+                params: ClientStateParams {
+                    trust_threshold: TrustThreshold::new(1, 1).unwrap(),
+                    ..default_params.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Invalid (empty) proof specs".to_string(),
                 params: ClientStateParams {
@@ -662,4 +829,43 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_builder() {
+        let chain_id = ChainId::new("ibc".to_string(), 1);
+        let latest_height = Height::new(1, 10).unwrap();
+
+        let client_state = ClientState::builder(chain_id.clone(), latest_height)
+            .build()
+            .unwrap();
+
+        assert_eq!(client_state.chain_id, chain_id);
+        assert_eq!(client_state.latest_height, latest_height);
+        assert_eq!(client_state.trust_threshold, TrustThreshold::TWO_THIRDS);
+        assert_eq!(
+            client_state.trusting_period,
+            2 * client_state.unbonding_period / 3
+        );
+        assert_eq!(client_state.frozen_height, None);
+
+        let client_state = ClientState::builder(chain_id.clone(), latest_height)
+            .with_trust_threshold(TrustThreshold::ONE_THIRD)
+            .with_unbonding_period(Duration::from_secs(300))
+            .with_max_clock_drift(Duration::from_secs(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(client_state.trust_threshold, TrustThreshold::ONE_THIRD);
+        assert_eq!(client_state.unbonding_period, Duration::from_secs(300));
+        assert_eq!(client_state.trusting_period, Duration::from_secs(200));
+        assert_eq!(client_state.max_clock_drift, Duration::from_secs(1));
+
+        // The builder validates its parameters like `ClientState::new`.
+        let res = ClientState::builder(chain_id, latest_height)
+            .with_trusting_period(Duration::from_secs(300))
+            .with_unbonding_period(Duration::from_secs(300))
+            .build();
+
+        assert!(res.is_err());
+    }
 }
@@ -144,6 +144,23 @@ impl From<Header> for ConsensusState {
     }
 }
 
+#[cfg(test)]
+pub mod test_util {
+    use tendermint::{Hash, Time};
+
+    use super::ConsensusState;
+    use crate::core::ics23_commitment::commitment::CommitmentRoot;
+
+    /// Returns a dummy Tendermint `ConsensusState`, for testing only!
+    pub fn dummy_consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            CommitmentRoot::from_bytes(&[0x01, 0x02, 0xab, 0xcd]),
+            Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+            Hash::Sha256([7; 32]),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tendermint_rpc::endpoint::abci_query::AbciQuery;
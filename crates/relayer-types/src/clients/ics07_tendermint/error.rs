@@ -22,6 +22,10 @@ define_error! {
             { reason: String }
             |e| { format_args!("invalid unbonding period: {}", e.reason) },
 
+        InvalidMaxClockDrift
+            { reason: String }
+            |e| { format_args!("invalid max clock drift: {}", e.reason) },
+
         InvalidAddress
             |_| { "invalid address" },
 
@@ -1,12 +1,14 @@
 use core::fmt::Debug;
 use std::time::Duration;
 
+use ibc_proto::google::protobuf::Any;
+
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics24_host::identifier::ChainId;
 
 use crate::Height;
 
-pub trait ClientState: Clone + Debug + Send + Sync {
+pub trait ClientState: Clone + Debug + Send + Sync + Into<Any> {
     /// Return the chain identifier which this client is serving (i.e., the client is verifying
     /// consensus states from this chain).
     fn chain_id(&self) -> ChainId;
@@ -28,6 +30,17 @@ pub trait ClientState: Clone + Debug + Send + Sync {
     /// Check if the state is expired when `elapsed` time has passed since the latest consensus
     /// state timestamp
     fn expired(&self, elapsed: Duration) -> bool;
+
+    /// Encode the client state as a protobuf `Any`, tagged with its type URL
+    fn encode_to_any(&self) -> Any {
+        self.clone().into()
+    }
+
+    /// Encode the client state to the deterministic protobuf bytes of its `Any` form,
+    /// as stored on chain
+    fn encode_any_vec(&self) -> Vec<u8> {
+        prost::Message::encode_to_vec(&self.encode_to_any())
+    }
 }
 
 pub trait UpgradableClientState: ClientState {
@@ -41,10 +41,13 @@ impl core::str::FromStr for ClientType {
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
+    use std::collections::HashMap;
     use test_log::test;
 
     use super::ClientType;
     use crate::core::ics02_client::error::{Error, ErrorDetail};
+    use crate::core::ics24_host::identifier::ClientId;
+    use crate::Height;
 
     #[test]
     fn parse_tendermint_client_type() {
@@ -56,6 +59,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn client_type_canonical_strings() {
+        let cases = [(ClientType::Tendermint, "07-tendermint")];
+
+        for (client_type, expected) in cases {
+            assert_eq!(client_type.as_str(), expected);
+            assert_eq!(ClientId::prefix(client_type), expected);
+            assert_eq!(client_type.to_string(), format!("ClientType({expected})"));
+            assert_eq!(
+                ClientId::new(client_type, 3).unwrap().as_str(),
+                format!("{expected}-3")
+            );
+        }
+    }
+
     #[test]
     fn parse_unknown_client_type() {
         let client_type_str = "some-random-client-type";
@@ -78,4 +96,25 @@ mod tests {
         let client_type_from_str = ClientType::from_str(type_string).unwrap();
         assert_eq!(client_type_from_str, client_type);
     }
+
+    #[test]
+    fn client_type_and_height_as_map_key() {
+        let mut map = HashMap::new();
+
+        let height = Height::new(1, 10).unwrap();
+        map.insert((ClientType::Tendermint, height), "a");
+        map.insert((ClientType::Tendermint, Height::new(1, 11).unwrap()), "b");
+
+        // Equal keys built independently must hash to the same entry.
+        let parsed = ClientType::from_str("07-tendermint").unwrap();
+        let same_height = Height::new(1, 10).unwrap();
+        assert_eq!(map.insert((parsed, same_height), "c"), Some("a"));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&(ClientType::Tendermint, height)), Some(&"c"));
+        assert_eq!(
+            map.get(&(ClientType::Tendermint, Height::new(2, 10).unwrap())),
+            None
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use core::fmt::Debug;
 
+use ibc_proto::google::protobuf::Any;
+
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics23_commitment::commitment::CommitmentRoot;
 use crate::timestamp::Timestamp;
@@ -11,8 +13,7 @@ use crate::timestamp::Timestamp;
 /// Effectively, that trait bound mandates implementers to derive PartialEq,
 /// after which our blanket implementation will implement
 /// `ErasedPartialEqConsensusState` for their type.
-pub trait ConsensusState: Clone + Debug + Send + Sync // Any: From<Self>,
-{
+pub trait ConsensusState: Clone + Debug + Send + Sync + Into<Any> {
     /// Type of client associated with this consensus state (eg. Tendermint)
     fn client_type(&self) -> ClientType;
 
@@ -21,4 +22,15 @@ pub trait ConsensusState: Clone + Debug + Send + Sync // Any: From<Self>,
 
     /// The timestamp of the consensus state
     fn timestamp(&self) -> Timestamp;
+
+    /// Encode the consensus state as a protobuf `Any`, tagged with its type URL
+    fn encode_to_any(&self) -> Any {
+        self.clone().into()
+    }
+
+    /// Encode the consensus state to the deterministic protobuf bytes of its `Any` form,
+    /// as stored on chain
+    fn encode_any_vec(&self) -> Vec<u8> {
+        prost::Message::encode_to_vec(&self.encode_to_any())
+    }
 }
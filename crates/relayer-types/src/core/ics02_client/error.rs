@@ -109,15 +109,17 @@ define_error! {
             },
 
         DecodeRawClientState
+            { type_url: String }
             [ TraceError<TendermintProtoError> ]
-            | _ | { "error decoding raw client state" },
+            | e | { format_args!("error decoding raw client state of type {0}", e.type_url) },
 
         MissingRawClientState
             | _ | { "missing raw client state" },
 
         InvalidRawConsensusState
+            { type_url: String }
             [ TraceError<TendermintProtoError> ]
-            | _ | { "invalid raw client consensus state" },
+            | e | { format_args!("error decoding raw client consensus state of type {0}", e.type_url) },
 
         MissingRawConsensusState
             | _ | { "missing raw client consensus state" },
@@ -201,14 +203,21 @@ define_error! {
                 format_args!("Insufficient overlap {}", e.reason)
             },
 
-        RawClientAndConsensusStateTypesMismatch
+        ClientConsensusTypeMismatch
             {
-                state_type: ClientType,
-                consensus_type: ClientType,
+                client: ClientType,
+                consensus: ClientType,
             }
             | e | {
-                format_args!("mismatch in raw client consensus state {} with expected state {}",
-                    e.state_type, e.consensus_type)
+                format_args!("client state of type {} cannot be paired with a consensus state of type {}",
+                    e.client.as_str(), e.consensus.as_str())
+            },
+
+        FrozenInitialClientState
+            { frozen_height: Height }
+            | e | {
+                format_args!("cannot create a client from a client state frozen at height {}",
+                    e.frozen_height)
             },
 
         LowHeaderHeight
@@ -289,3 +298,44 @@ define_error! {
             | e | { format_args!("event attribute value for key {} is not valid UTF-8", e.key) },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    fn assert_send_sync_error<E: std::error::Error + Send + Sync + 'static>() {}
+
+    #[test]
+    fn error_is_send_sync_static() {
+        assert_send_sync_error::<Error>();
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> =
+            Box::new(Error::client_not_found("07-tendermint-0".parse().unwrap()));
+
+        assert!(boxed
+            .to_string()
+            .starts_with("client not found: 07-tendermint-0"));
+    }
+
+    #[test]
+    fn error_preserves_source_chain() {
+        let err = Error::invalid_raw_client_id("/".to_string(), ValidationError::empty());
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(err);
+
+        let mut chain = Vec::new();
+        let mut current = boxed.source();
+        while let Some(source) = current {
+            chain.push(source.to_string());
+            current = source.source();
+        }
+
+        assert!(
+            chain
+                .iter()
+                .any(|msg| msg.starts_with("identifier cannot be empty")),
+            "source chain should contain the validation error: {chain:?}"
+        );
+    }
+}
@@ -264,3 +264,61 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use tendermint::abci;
+
+    use super::*;
+
+    fn attribute_pairs(event: &abci::Event) -> Vec<(&str, &str)> {
+        event
+            .attributes
+            .iter()
+            .map(|attr| (attr.key_str().unwrap(), attr.value_str().unwrap()))
+            .collect()
+    }
+
+    fn dummy_attributes() -> Attributes {
+        Attributes {
+            client_id: "07-tendermint-3".parse().unwrap(),
+            client_type: ClientType::Tendermint,
+            consensus_height: Height::new(1, 42).unwrap(),
+        }
+    }
+
+    #[test]
+    fn client_events_to_abci_event() {
+        let expected_attributes = vec![
+            (CLIENT_ID_ATTRIBUTE_KEY, "07-tendermint-3"),
+            (CLIENT_TYPE_ATTRIBUTE_KEY, "07-tendermint"),
+            (CONSENSUS_HEIGHT_ATTRIBUTE_KEY, "1-42"),
+        ];
+
+        let events = [
+            (
+                abci::Event::from(CreateClient::from(dummy_attributes())),
+                "create_client",
+            ),
+            (
+                abci::Event::from(UpdateClient::from(dummy_attributes())),
+                "update_client",
+            ),
+            (
+                abci::Event::from(ClientMisbehaviour::from(dummy_attributes())),
+                "client_misbehaviour",
+            ),
+            (
+                abci::Event::from(UpgradeClient::from(dummy_attributes())),
+                "upgrade_client",
+            ),
+        ];
+
+        for (event, kind) in events {
+            assert_eq!(event.kind, kind);
+            assert_eq!(attribute_pairs(&event), expected_attributes);
+        }
+    }
+}
@@ -98,3 +98,42 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl TryFrom<AnyHeader> for TendermintHeader {
+    type Error = Error;
+
+    fn try_from(header: AnyHeader) -> Result<Self, Error> {
+        match header {
+            AnyHeader::Tendermint(header) => Ok(header),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use tendermint_testgen::{Generator, LightBlock};
+
+    use super::{AnyHeader, TendermintHeader};
+    use crate::Height;
+
+    fn dummy_tendermint_header() -> TendermintHeader {
+        let light_block = LightBlock::new_default(10).generate().unwrap();
+
+        TendermintHeader {
+            signed_header: light_block.signed_header,
+            validator_set: light_block.validators,
+            trusted_height: Height::new(0, 5).unwrap(),
+            trusted_validator_set: light_block.next_validators,
+        }
+    }
+
+    #[test]
+    fn any_header_into_tendermint_header() {
+        let header = dummy_tendermint_header();
+        let any_header = AnyHeader::from(header.clone());
+
+        assert_eq!(TendermintHeader::try_from(any_header).unwrap(), header);
+    }
+}
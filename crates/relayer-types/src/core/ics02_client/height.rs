@@ -189,3 +189,96 @@ impl FromStr for Height {
             .map_err(|_| HeightError::invalid_height(value.to_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::{Height, HeightErrorDetail};
+
+    #[test]
+    fn parse_height() {
+        struct Test {
+            name: String,
+            input: &'static str,
+            want_pass: bool,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good height".to_string(),
+                input: "1-234",
+                want_pass: true,
+            },
+            Test {
+                name: "Good height at revision zero".to_string(),
+                input: "0-1",
+                want_pass: true,
+            },
+            Test {
+                name: "Bad height zero".to_string(),
+                input: "0-0",
+                want_pass: false,
+            },
+            Test {
+                name: "Bad height without revision".to_string(),
+                input: "234",
+                want_pass: false,
+            },
+            Test {
+                name: "Bad height with extra component".to_string(),
+                input: "1-2-3",
+                want_pass: false,
+            },
+            Test {
+                name: "Bad height with negative revision".to_string(),
+                input: "-1-234",
+                want_pass: false,
+            },
+            Test {
+                name: "Bad height not a number".to_string(),
+                input: "1-abc",
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = test.input.parse::<Height>();
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "parse_height failed for test {}, with result {:?}",
+                test.name,
+                res
+            );
+        }
+
+        let height: Height = "1-234".parse().unwrap();
+        assert_eq!(height.revision_number(), 1);
+        assert_eq!(height.revision_height(), 234);
+
+        assert!(matches!(
+            "0-0".parse::<Height>().unwrap_err().detail(),
+            HeightErrorDetail::ZeroHeight(_)
+        ));
+    }
+
+    #[test]
+    fn height_ordering_across_revisions() {
+        let before_upgrade = Height::new(1, 1_000_000).unwrap();
+        let after_upgrade = Height::new(2, 1).unwrap();
+
+        assert!(before_upgrade < after_upgrade);
+        assert!(Height::new(2, 1).unwrap() < Height::new(2, 2).unwrap());
+        assert_eq!(before_upgrade.max(after_upgrade), after_upgrade);
+    }
+
+    #[test]
+    fn height_display_roundtrip() {
+        let height = Height::new(4, 1_234).unwrap();
+
+        assert_eq!(height.to_string(), "4-1234");
+        assert_eq!(height.to_string().parse::<Height>().unwrap(), height);
+    }
+}
@@ -4,6 +4,8 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
 use ibc_proto::Protobuf;
 
+use crate::core::ics02_client::client_state::ClientState;
+use crate::core::ics02_client::consensus_state::ConsensusState;
 use crate::core::ics02_client::error::Error;
 use crate::signer::Signer;
 use crate::tx_msg::Msg;
@@ -26,6 +28,35 @@ impl MsgCreateClient {
             signer,
         })
     }
+
+    /// Builds the message from the initial client and consensus states, checking
+    /// that both are for the same type of client and that the client is not frozen.
+    pub fn from_states<CS, CSt>(
+        client_state: CS,
+        consensus_state: CSt,
+        signer: Signer,
+    ) -> Result<Self, Error>
+    where
+        CS: ClientState,
+        CSt: ConsensusState,
+    {
+        if client_state.client_type() != consensus_state.client_type() {
+            return Err(Error::client_consensus_type_mismatch(
+                client_state.client_type(),
+                consensus_state.client_type(),
+            ));
+        }
+
+        if let Some(frozen_height) = client_state.frozen_height() {
+            return Err(Error::frozen_initial_client_state(frozen_height));
+        }
+
+        Self::new(
+            client_state.encode_to_any(),
+            consensus_state.encode_to_any(),
+            signer,
+        )
+    }
 }
 
 impl Msg for MsgCreateClient {
@@ -72,3 +103,68 @@ impl From<MsgCreateClient> for RawMsgCreateClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+    use crate::clients::ics07_tendermint::consensus_state::test_util::dummy_consensus_state;
+    use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+    use crate::core::ics02_client::error::ErrorDetail;
+    use crate::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::signer::Signer;
+    use crate::Height;
+
+    fn dummy_states() -> (TmClientState, TmConsensusState) {
+        let client_state = TmClientState::builder(
+            ChainId::new("ibc".to_string(), 0),
+            Height::new(0, 10).unwrap(),
+        )
+        .build()
+        .unwrap();
+
+        (client_state, dummy_consensus_state())
+    }
+
+    #[test]
+    fn create_client_from_states() {
+        let (client_state, consensus_state) = dummy_states();
+        let signer: Signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            .parse()
+            .unwrap();
+
+        let msg = MsgCreateClient::from_states(
+            client_state.clone(),
+            consensus_state.clone(),
+            signer.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(msg.client_state, client_state.into());
+        assert_eq!(msg.consensus_state, consensus_state.into());
+        assert_eq!(msg.signer, signer);
+    }
+
+    #[test]
+    fn create_client_rejects_frozen_client_state() {
+        let (client_state, consensus_state) = dummy_states();
+        let frozen_height = Height::new(0, 5).unwrap();
+        let client_state = client_state.with_frozen_height(frozen_height).unwrap();
+
+        let err = MsgCreateClient::from_states(
+            client_state,
+            consensus_state,
+            "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap_err();
+
+        match err.detail() {
+            ErrorDetail::FrozenInitialClientState(e) => assert_eq!(e.frozen_height, frozen_height),
+            _ => panic!("expected a frozen client state error, got {err}"),
+        }
+    }
+}
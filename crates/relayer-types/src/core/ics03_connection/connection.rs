@@ -4,15 +4,17 @@ use std::time::Duration;
 
 use ibc_proto::Protobuf;
 use serde::{Deserialize, Serialize};
+use subtle_encoding::{Encoding, Hex};
 
 use ibc_proto::ibc::core::connection::v1::{
     ConnectionEnd as RawConnectionEnd, Counterparty as RawCounterparty,
-    IdentifiedConnection as RawIdentifiedConnection,
+    IdentifiedConnection as RawIdentifiedConnection, Version as RawVersion,
 };
 
 use crate::core::ics02_client::error::Error as ClientError;
 use crate::core::ics03_connection::error::Error;
 use crate::core::ics03_connection::version::Version;
+use crate::core::ics04_channel::channel::Ordering;
 use crate::core::ics23_commitment::commitment::CommitmentPrefix;
 use crate::core::ics24_host::error::ValidationError;
 use crate::core::ics24_host::identifier::{ClientId, ConnectionId};
@@ -147,6 +149,133 @@ impl From<ConnectionEnd> for RawConnectionEnd {
     }
 }
 
+/// Compact, single-line textual form of a connection end, meant for quick
+/// inspection and debugging. It round-trips through [`FromStr`], e.g.
+///
+/// `OPEN 07-tendermint-0 07-tendermint-1/connection-1 prefix=696263 versions=1:ORDER_ORDERED|ORDER_UNORDERED delay=0`
+///
+/// The counterparty connection id is omitted when unset, the counterparty
+/// prefix is hex-encoded and the delay period is expressed in nanoseconds.
+/// Use the serde implementation for a stable machine-readable format.
+impl Display for ConnectionEnd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{} {} {}",
+            self.state, self.client_id, self.counterparty.client_id
+        )?;
+
+        if let Some(connection_id) = &self.counterparty.connection_id {
+            write!(f, "/{connection_id}")?;
+        }
+
+        let prefix = Hex::upper_case()
+            .encode_to_string(self.counterparty.prefix.as_bytes())
+            .map_err(|_| FmtError)?;
+
+        let versions = self
+            .versions
+            .iter()
+            .map(|v| {
+                let raw = RawVersion::from(v.clone());
+                format!("{}:{}", raw.identifier, raw.features.join("|"))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(
+            f,
+            " prefix={} versions={} delay={}",
+            prefix,
+            versions,
+            self.delay_period.as_nanos()
+        )
+    }
+}
+
+impl FromStr for ConnectionEnd {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn field<'a>(token: &'a str, key: &str) -> Option<&'a str> {
+            token.strip_prefix(key)?.strip_prefix('=')
+        }
+
+        let invalid =
+            |reason: &str| Error::invalid_connection_end_string(s.to_string(), reason.to_string());
+
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        let [state, client_id, counterparty, prefix, versions, delay] = tokens[..] else {
+            return Err(invalid("expected 6 space-separated fields"));
+        };
+
+        let state = State::from_str(state)?;
+        let client_id = client_id.parse().map_err(Error::invalid_identifier)?;
+
+        let (cp_client_id, cp_connection_id) = match counterparty.split_once('/') {
+            Some((client_id, connection_id)) => (client_id, Some(connection_id)),
+            None => (counterparty, None),
+        };
+
+        let prefix = Hex::upper_case()
+            .decode(
+                field(prefix, "prefix")
+                    .ok_or_else(|| invalid("missing `prefix=` field"))?
+                    .to_uppercase(),
+            )
+            .map_err(|_| invalid("prefix is not valid hex"))?;
+        let prefix = if prefix.is_empty() {
+            CommitmentPrefix::default()
+        } else {
+            CommitmentPrefix::new(prefix)
+                .map_err(|_| Error::ics02_client(ClientError::empty_prefix()))?
+        };
+
+        let counterparty = Counterparty::new(
+            cp_client_id.parse().map_err(Error::invalid_identifier)?,
+            cp_connection_id
+                .map(ConnectionId::from_str)
+                .transpose()
+                .map_err(Error::invalid_identifier)?,
+            prefix,
+        );
+
+        let versions = field(versions, "versions")
+            .ok_or_else(|| invalid("missing `versions=` field"))?
+            .split(',')
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                let (identifier, features) = v
+                    .split_once(':')
+                    .ok_or_else(|| invalid("version must be of the form `identifier:features`"))?;
+
+                Version::try_from(RawVersion {
+                    identifier: identifier.to_string(),
+                    features: features
+                        .split('|')
+                        .filter(|f| !f.is_empty())
+                        .map(ToString::to_string)
+                        .collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let delay_period = field(delay, "delay")
+            .ok_or_else(|| invalid("missing `delay=` field"))?
+            .parse()
+            .map(Duration::from_nanos)
+            .map_err(|_| invalid("delay must be a number of nanoseconds"))?;
+
+        Ok(Self::new(
+            state,
+            client_id,
+            counterparty,
+            versions,
+            delay_period,
+        ))
+    }
+}
+
 impl ConnectionEnd {
     pub fn new(
         state: State,
@@ -219,6 +348,31 @@ impl ConnectionEnd {
         &self.versions
     }
 
+    /// Channel orderings supported by this connection, as advertised by the features
+    /// of its negotiated version. Returns an empty list while the connection still
+    /// carries more than one candidate version, i.e. before version negotiation.
+    pub fn supported_orderings(&self) -> Vec<Ordering> {
+        let [version] = self.versions.as_slice() else {
+            return vec![];
+        };
+
+        [Ordering::Ordered, Ordering::Unordered]
+            .into_iter()
+            .filter(|ordering| version.supports(ordering.as_str()))
+            .collect()
+    }
+
+    /// Iterates over the versions of this connection end which support the given feature,
+    /// e.g. a channel ordering such as `ORDER_ORDERED`.
+    pub fn versions_supporting<'a>(
+        &'a self,
+        feature: &'a str,
+    ) -> impl Iterator<Item = &'a Version> + 'a {
+        self.versions
+            .iter()
+            .filter(move |version| version.supports(feature))
+    }
+
     /// Getter for the counterparty.
     pub fn counterparty(&self) -> &Counterparty {
         &self.counterparty
@@ -230,9 +384,24 @@ impl ConnectionEnd {
         self.delay_period
     }
 
-    /// TODO: Clean this up, probably not necessary.
-    pub fn validate_basic(&self) -> Result<(), ValidationError> {
-        self.counterparty.validate_basic()
+    /// Performs basic validation of this connection end: it must be initialized,
+    /// refer to a client and advertise at least one version.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.is_uninitialized() {
+            return Err(Error::uninitialized_connection_end());
+        }
+
+        if self.client_id.as_str().is_empty() {
+            return Err(Error::invalid_identifier(ValidationError::empty()));
+        }
+
+        if self.versions.is_empty() {
+            return Err(Error::empty_versions());
+        }
+
+        self.counterparty
+            .validate_basic()
+            .map_err(Error::invalid_identifier)
     }
 }
 
@@ -259,12 +428,13 @@ impl TryFrom<RawCounterparty> for Counterparty {
         Ok(Counterparty::new(
             value.client_id.parse().map_err(Error::invalid_identifier)?,
             connection_id,
-            value
-                .prefix
-                .ok_or_else(Error::missing_counterparty)?
-                .key_prefix
-                .try_into()
-                .map_err(|_| Error::ics02_client(ClientError::empty_prefix()))?,
+            CommitmentPrefix::new(
+                value
+                    .prefix
+                    .ok_or_else(Error::missing_counterparty)?
+                    .key_prefix,
+            )
+            .map_err(|_| Error::ics02_client(ClientError::empty_prefix()))?,
         ))
     }
 }
@@ -370,6 +540,23 @@ impl Display for State {
     }
 }
 
+impl FromStr for State {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().trim_start_matches("STATE_") {
+            "UNINITIALIZED" | "UNINITIALIZED_UNSPECIFIED" => Ok(Self::Uninitialized),
+            "INIT" => Ok(Self::Init),
+            "TRYOPEN" => Ok(Self::TryOpen),
+            "OPEN" => Ok(Self::Open),
+            _ => Err(Error::invalid_connection_end_string(
+                s.to_string(),
+                "unknown connection state".to_string(),
+            )),
+        }
+    }
+}
+
 impl TryFrom<i32> for State {
     type Error = Error;
     fn try_from(value: i32) -> Result<Self, Self::Error> {
@@ -388,3 +575,216 @@ impl From<State> for i32 {
         value as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ibc_proto::ibc::core::connection::v1::{
+        ConnectionEnd as RawConnectionEnd, Version as RawVersion,
+    };
+    use ibc_proto::Protobuf;
+    use test_log::test;
+
+    use crate::core::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+    use crate::core::ics03_connection::msgs::test_util::get_dummy_raw_counterparty;
+    use crate::core::ics03_connection::version::Version;
+    use crate::core::ics04_channel::channel::Ordering;
+    use crate::core::ics24_host::identifier::ClientId;
+
+    fn dummy_connection_end(state: State, versions: Vec<Version>) -> ConnectionEnd {
+        ConnectionEnd::new(
+            state,
+            ClientId::default(),
+            Counterparty::try_from(get_dummy_raw_counterparty()).unwrap(),
+            versions,
+            Duration::from_secs(0),
+        )
+    }
+
+    #[test]
+    fn validate_basic() {
+        struct Test {
+            name: String,
+            connection_end: ConnectionEnd,
+            want_pass: bool,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Open connection with default version".to_string(),
+                connection_end: dummy_connection_end(State::Open, vec![Version::default()]),
+                want_pass: true,
+            },
+            Test {
+                name: "Connection in Init with default version".to_string(),
+                connection_end: dummy_connection_end(State::Init, vec![Version::default()]),
+                want_pass: true,
+            },
+            Test {
+                name: "Connection without any version".to_string(),
+                connection_end: dummy_connection_end(State::Open, vec![]),
+                want_pass: false,
+            },
+            Test {
+                name: "Uninitialized connection".to_string(),
+                connection_end: dummy_connection_end(
+                    State::Uninitialized,
+                    vec![Version::default()],
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Default connection end".to_string(),
+                connection_end: ConnectionEnd::default(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = test.connection_end.validate_basic();
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "ConnectionEnd::validate_basic() failed for test {}, \nconnection end {:?} with error {:?}",
+                test.name,
+                test.connection_end,
+                res.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn state_helpers() {
+        let open = dummy_connection_end(State::Open, vec![Version::default()]);
+        assert!(open.is_open());
+        assert!(open.state_matches(&State::Open));
+        assert!(!open.state_matches(&State::TryOpen));
+
+        let try_open = dummy_connection_end(State::TryOpen, vec![Version::default()]);
+        assert!(!try_open.is_open());
+        assert!(try_open.state_matches(&State::TryOpen));
+        assert!(!try_open.is_uninitialized());
+    }
+
+    #[test]
+    fn text_form_roundtrip() {
+        let open = ConnectionEnd::new(
+            State::Open,
+            ClientId::default(),
+            Counterparty::try_from(get_dummy_raw_counterparty()).unwrap(),
+            vec![Version::default()],
+            Duration::from_secs(10),
+        );
+
+        let text = open.to_string();
+        assert_eq!(
+            text,
+            "OPEN 07-tendermint-0 07-tendermint-0/connection-0 prefix=696263 \
+             versions=1:ORDER_ORDERED|ORDER_UNORDERED delay=10000000000"
+        );
+        assert_eq!(text.parse::<ConnectionEnd>().unwrap(), open);
+
+        let default = ConnectionEnd::default();
+        assert_eq!(
+            default.to_string().parse::<ConnectionEnd>().unwrap(),
+            default
+        );
+    }
+
+    #[test]
+    fn text_form_parse_errors() {
+        let inputs = [
+            "",
+            "OPEN 07-tendermint-0",
+            "CLOSED 07-tendermint-0 07-tendermint-0 prefix=696263 versions=1:ORDER_ORDERED delay=0",
+            "OPEN 07-tendermint-0 07-tendermint-0 696263 versions=1:ORDER_ORDERED delay=0",
+            "OPEN 07-tendermint-0 07-tendermint-0 prefix=xyz versions=1:ORDER_ORDERED delay=0",
+            "OPEN 07-tendermint-0 07-tendermint-0 prefix=696263 versions=1 delay=0",
+            "OPEN 07-tendermint-0 07-tendermint-0 prefix=696263 versions=1:ORDER_ORDERED delay=-1",
+        ];
+
+        for input in inputs {
+            assert!(
+                input.parse::<ConnectionEnd>().is_err(),
+                "parsing should fail for {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn supported_orderings() {
+        let unordered_only = Version::try_from(RawVersion {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        })
+        .unwrap();
+
+        let both = dummy_connection_end(State::Open, vec![Version::default()]);
+        assert_eq!(
+            both.supported_orderings(),
+            vec![Ordering::Ordered, Ordering::Unordered]
+        );
+
+        let unordered = dummy_connection_end(State::Open, vec![unordered_only.clone()]);
+        assert_eq!(unordered.supported_orderings(), vec![Ordering::Unordered]);
+
+        let not_negotiated =
+            dummy_connection_end(State::Init, vec![Version::default(), unordered_only]);
+        assert!(not_negotiated.supported_orderings().is_empty());
+    }
+
+    #[test]
+    fn encode_vec_matches_fixture() {
+        let open = ConnectionEnd::new(
+            State::Open,
+            ClientId::default(),
+            Counterparty::try_from(get_dummy_raw_counterparty()).unwrap(),
+            vec![Version::default()],
+            Duration::from_secs(10),
+        );
+
+        // field by field: client id, version, state, counterparty, delay period
+        let expected = [
+            b"\x0a\x0f07-tendermint-0".as_slice(),
+            b"\x12\x23\x0a\x011\x12\x0dORDER_ORDERED\x12\x0fORDER_UNORDERED",
+            b"\x18\x03",
+            b"\x22\x26\x0a\x0f07-tendermint-0\x12\x0cconnection-0\x1a\x05\x0a\x03ibc",
+            b"\x28\x80\xc8\xaf\xa0\x25",
+        ]
+        .concat();
+
+        let bytes = Protobuf::<RawConnectionEnd>::encode_vec(open.clone());
+        assert_eq!(bytes, expected);
+        assert_eq!(bytes, Protobuf::<RawConnectionEnd>::encode_vec(open));
+    }
+
+    #[test]
+    fn versions_supporting_feature() {
+        let unordered_only = Version::try_from(RawVersion {
+            identifier: "2".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        })
+        .unwrap();
+        let connection_end = dummy_connection_end(
+            State::Init,
+            vec![Version::default(), unordered_only.clone()],
+        );
+
+        let ordered: Vec<_> = connection_end
+            .versions_supporting(Ordering::Ordered.as_str())
+            .collect();
+        assert_eq!(ordered, vec![&Version::default()]);
+
+        let unordered: Vec<_> = connection_end
+            .versions_supporting(Ordering::Unordered.as_str())
+            .collect();
+        assert_eq!(unordered, vec![&Version::default(), &unordered_only]);
+
+        assert_eq!(
+            connection_end.versions_supporting("ORDER_RANDOM").count(),
+            0
+        );
+    }
+}
@@ -57,6 +57,16 @@ define_error! {
             [ ValidationError ]
             | _ | { "identifier error" },
 
+        UninitializedConnectionEnd
+            | _ | { "connection end is uninitialized" },
+
+        InvalidConnectionEndString
+            { input: String, reason: String }
+            | e | {
+                format_args!("invalid connection end string \"{}\": {}",
+                    e.input, e.reason)
+            },
+
         EmptyProtoConnectionEnd
             | _ | { "ConnectionEnd domain object could not be constructed out of empty proto object" },
 
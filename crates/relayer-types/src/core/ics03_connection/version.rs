@@ -21,7 +21,13 @@ pub struct Version {
 impl Version {
     /// Checks whether or not the given feature is supported in this version
     pub fn is_supported_feature(&self, feature: String) -> bool {
-        self.features.contains(&feature)
+        self.supports(&feature)
+    }
+
+    /// Checks whether or not the given feature is supported in this version,
+    /// without requiring an owned `String`
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
     }
 }
 
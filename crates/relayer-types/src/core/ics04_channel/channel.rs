@@ -227,6 +227,9 @@ impl ChannelEnd {
     }
 
     pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.ordering == Ordering::Uninitialized {
+            return Err(Error::uninitialized_ordering());
+        }
         if self.connection_hops.len() != 1 {
             return Err(Error::invalid_connection_hops_length(
                 1,
@@ -569,7 +572,8 @@ mod tests {
     use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
 
     use crate::core::ics04_channel::channel::test_util::get_dummy_raw_channel_end;
-    use crate::core::ics04_channel::channel::ChannelEnd;
+    use crate::core::ics04_channel::channel::{ChannelEnd, Ordering, State, UpgradeState};
+    use crate::core::ics24_host::identifier::ConnectionId;
 
     #[test]
     fn channel_end_try_from_raw() {
@@ -660,6 +664,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_end_validate_basic() {
+        struct Test {
+            name: String,
+            channel_end: ChannelEnd,
+            want_pass: bool,
+        }
+
+        let channel_end = ChannelEnd::try_from(get_dummy_raw_channel_end()).unwrap();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Well-formed channel end".to_string(),
+                channel_end: channel_end.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Channel end without connection hops".to_string(),
+                channel_end: ChannelEnd {
+                    connection_hops: vec![],
+                    ..channel_end.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Channel end with two connection hops".to_string(),
+                channel_end: ChannelEnd {
+                    connection_hops: vec![ConnectionId::new(0), ConnectionId::new(1)],
+                    ..channel_end.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Channel end with uninitialized ordering".to_string(),
+                channel_end: ChannelEnd {
+                    ordering: Ordering::Uninitialized,
+                    ..channel_end
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = test.channel_end.validate_basic();
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "ChannelEnd::validate_basic() failed for test {}, \nchannel end {:?} with error {:?}",
+                test.name,
+                test.channel_end,
+                res.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn channel_end_state_helpers() {
+        let mut channel_end = ChannelEnd::try_from(get_dummy_raw_channel_end()).unwrap();
+        assert!(channel_end.state_matches(&State::Init));
+        assert!(!channel_end.is_open());
+
+        channel_end.set_state(State::Open(UpgradeState::NotUpgrading));
+        assert!(channel_end.is_open());
+
+        channel_end.set_state(State::Open(UpgradeState::Upgrading));
+        assert!(!channel_end.is_open());
+        assert!(channel_end.state_matches(&State::Open(UpgradeState::Upgrading)));
+    }
+
     #[test]
     fn parse_channel_ordering_type() {
         use super::Ordering;
@@ -42,6 +42,9 @@ define_error! {
             { type_id: String }
             | e | { format_args!("channel order type unknown: {}", e.type_id) },
 
+        UninitializedOrdering
+            | _ | { "channel ordering cannot be uninitialized" },
+
         InvalidConnectionHopsLength
             { expected: usize, actual: usize }
             | e | {
@@ -92,6 +95,9 @@ define_error! {
         ZeroPacketData
             | _ | { "packet data bytes cannot be empty" },
 
+        MissingPacketTimeout
+            | _ | { "packet timeout height and timeout timestamp cannot both be unset" },
+
         InvalidTimeoutHeight
             | _ | { "invalid timeout height for the packet" },
 
@@ -3,10 +3,13 @@ use std::str::FromStr;
 use serde_derive::{Deserialize, Serialize};
 
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+use ics23::{HostFunctionsManager, HostFunctionsProvider};
 
 use super::timeout::TimeoutHeight;
+use crate::core::ics04_channel::commitment::PacketCommitment;
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::core::ics24_host::validate::{validate_channel_identifier, validate_port_identifier};
 use crate::timestamp::{Expiry::Expired, Timestamp};
 use crate::Height;
 
@@ -189,6 +192,67 @@ impl Packet {
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Computes the commitment to this packet, as stored by the sending chain:
+    /// `sha256(timeout_timestamp || timeout_revision_number || timeout_revision_height || sha256(data))`,
+    /// with every integer encoded as big-endian `u64`.
+    pub fn commitment(&self) -> PacketCommitment {
+        let mut bytes = Vec::with_capacity(3 * 8 + 32);
+        bytes.extend_from_slice(&self.timeout_timestamp.nanoseconds().to_be_bytes());
+        bytes.extend_from_slice(
+            &self
+                .timeout_height
+                .commitment_revision_number()
+                .to_be_bytes(),
+        );
+        bytes.extend_from_slice(
+            &self
+                .timeout_height
+                .commitment_revision_height()
+                .to_be_bytes(),
+        );
+        bytes.extend_from_slice(&HostFunctionsManager::sha2_256(&self.data));
+
+        HostFunctionsManager::sha2_256(&bytes).to_vec().into()
+    }
+
+    /// Checks that the commitment stored on the sending chain matches the
+    /// commitment computed from this packet, e.g. before relaying an
+    /// acknowledgement or a timeout for it.
+    pub fn verify_commitment(&self, stored: &PacketCommitment) -> Result<(), Error> {
+        if &self.commitment() != stored {
+            return Err(Error::incorrect_packet_commitment(self.sequence));
+        }
+
+        Ok(())
+    }
+
+    /// Performs the stateless checks on the packet fields: the sequence and
+    /// data must be non-zero, the port and channel identifiers must be valid,
+    /// and at least one of the timeout height or timeout timestamp must be set.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.sequence.is_zero() {
+            return Err(Error::zero_packet_sequence());
+        }
+
+        validate_port_identifier(self.source_port.as_str()).map_err(Error::identifier)?;
+        validate_channel_identifier(self.source_channel.as_str()).map_err(Error::identifier)?;
+        validate_port_identifier(self.destination_port.as_str()).map_err(Error::identifier)?;
+        validate_channel_identifier(self.destination_channel.as_str())
+            .map_err(Error::identifier)?;
+
+        if self.data.is_empty() {
+            return Err(Error::zero_packet_data());
+        }
+
+        if self.timeout_height == TimeoutHeight::Never
+            && self.timeout_timestamp == Timestamp::none()
+        {
+            return Err(Error::missing_packet_timeout());
+        }
+
+        Ok(())
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -303,9 +367,13 @@ mod tests {
 
     use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+    use subtle_encoding::{Encoding, Hex};
 
     use crate::core::ics04_channel::packet::test_utils::get_dummy_raw_packet;
     use crate::core::ics04_channel::packet::Packet;
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::core::ics24_host::identifier::ChannelId;
+    use crate::timestamp::Timestamp;
 
     #[test]
     fn packet_try_from_raw() {
@@ -479,4 +547,115 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    #[test]
+    fn packet_validate_basic() {
+        struct Test {
+            name: String,
+            packet: Packet,
+            want_pass: bool,
+        }
+
+        let default_packet = Packet::try_from(get_dummy_raw_packet(10, 0)).unwrap();
+
+        // Identifiers are only validated on parsing, so an empty one can still
+        // make its way into a packet through deserialization.
+        let empty_channel_id: ChannelId = serde_json::from_str("\"\"").unwrap();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                packet: default_packet.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Only timeout timestamp set".to_string(),
+                packet: Packet {
+                    timeout_height: TimeoutHeight::no_timeout(),
+                    timeout_timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+                    ..default_packet.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Missing both timeout height and timestamp".to_string(),
+                packet: Packet {
+                    timeout_height: TimeoutHeight::no_timeout(),
+                    timeout_timestamp: Timestamp::none(),
+                    ..default_packet.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty source channel id".to_string(),
+                packet: Packet {
+                    source_channel: empty_channel_id.clone(),
+                    ..default_packet.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty destination channel id".to_string(),
+                packet: Packet {
+                    destination_channel: empty_channel_id,
+                    ..default_packet.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty packet data".to_string(),
+                packet: Packet {
+                    data: vec![],
+                    ..default_packet.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Zero sequence".to_string(),
+                packet: Packet {
+                    sequence: 0u64.into(),
+                    ..default_packet
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = test.packet.validate_basic();
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "Packet::validate_basic failed for test {}, \npacket {:?} with error {:?}",
+                test.name,
+                test.packet,
+                res.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn packet_commitment() {
+        let packet = Packet::try_from(get_dummy_raw_packet(10, 5)).unwrap();
+
+        // sha256(5u64 || 0u64 || 10u64 || sha256([0]))
+        let commitment = packet.commitment();
+        assert_eq!(
+            Hex::upper_case().encode_to_string(&commitment).unwrap(),
+            "66D9891A82E3639B95C5F4F4CCDC74AC79FE51021D9E430ED8F171419B0D2F13"
+        );
+        packet.verify_commitment(&commitment).unwrap();
+
+        let tampered = Packet {
+            data: vec![1],
+            ..packet.clone()
+        };
+        assert!(tampered.verify_commitment(&commitment).is_err());
+
+        let tampered = Packet {
+            timeout_timestamp: Timestamp::from_nanoseconds(6).unwrap(),
+            ..packet
+        };
+        assert!(tampered.verify_commitment(&commitment).is_err());
+    }
 }
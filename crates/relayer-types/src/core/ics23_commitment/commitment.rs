@@ -13,7 +13,10 @@ use super::merkle::MerkleProof;
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CommitmentRoot {
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::deser_hex_upper"
+    )]
     bytes: Vec<u8>,
 }
 
@@ -31,6 +34,14 @@ impl CommitmentRoot {
         }
     }
 
+    /// Parses a commitment root from its hex encoding, in either case.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        Hex::upper_case()
+            .decode(hex.to_uppercase())
+            .map(Self::from)
+            .map_err(|_| Error::invalid_hex_commitment_root(hex.to_string()))
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
@@ -124,12 +135,45 @@ impl TryFrom<CommitmentProofBytes> for RawMerkleProof {
     }
 }
 
+/// Decodes the proof bytes carried in IBC messages into a [`MerkleProof`]
+/// ready for verification, rejecting proofs without any commitment proof.
+impl TryFrom<CommitmentProofBytes> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(value: CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let raw = RawMerkleProof::try_from(value)?;
+        if raw.proofs.is_empty() {
+            return Err(Error::empty_merkle_proof());
+        }
+        if raw.proofs.iter().any(|proof| proof.proof.is_none()) {
+            return Err(Error::invalid_merkle_proof());
+        }
+        Ok(Self::from(raw))
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Deserialize, Default)]
 pub struct CommitmentPrefix {
     bytes: Vec<u8>,
 }
 
 impl CommitmentPrefix {
+    /// Builds a commitment prefix, rejecting an empty one as required by ICS-23.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        if bytes.is_empty() {
+            Err(Error::empty_commitment_prefix())
+        } else {
+            Ok(Self { bytes })
+        }
+    }
+
+    /// The `ibc` store prefix used by Cosmos SDK chains.
+    pub fn cosmos_default() -> Self {
+        Self {
+            bytes: b"ibc".to_vec(),
+        }
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
@@ -143,11 +187,7 @@ impl TryFrom<Vec<u8>> for CommitmentPrefix {
     type Error = Error;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-        if bytes.is_empty() {
-            Err(Self::Error::empty_commitment_prefix())
-        } else {
-            Ok(Self { bytes })
-        }
+        Self::new(bytes)
     }
 }
 
@@ -183,3 +223,106 @@ pub mod test_util {
         RawMerkleProof { proofs: mproofs }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use ics23::commitment_proof::Proof;
+    use ics23::{CommitmentProof, ExistenceProof, HashOp, InnerOp};
+
+    use super::test_util::get_dummy_merkle_proof;
+    use super::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+    use crate::core::ics23_commitment::error::ErrorDetail;
+    use crate::core::ics23_commitment::merkle::MerkleProof;
+
+    #[test]
+    fn commitment_root_bytes_roundtrip() {
+        let bytes = vec![0x01, 0x02, 0xab, 0xcd];
+
+        let root = CommitmentRoot::from_bytes(&bytes);
+        assert_eq!(root.as_bytes(), bytes.as_slice());
+        assert_eq!(root.clone().into_vec(), bytes);
+        assert_eq!(CommitmentRoot::from(bytes), root);
+    }
+
+    #[test]
+    fn commitment_root_from_hex() {
+        let expected = CommitmentRoot::from_bytes(&[0x01, 0x02, 0xab, 0xcd]);
+
+        assert_eq!(CommitmentRoot::from_hex("0102ABCD").unwrap(), expected);
+        assert_eq!(CommitmentRoot::from_hex("0102abcd").unwrap(), expected);
+
+        assert!(CommitmentRoot::from_hex("0102ABC").is_err());
+        assert!(CommitmentRoot::from_hex("not hex").is_err());
+    }
+
+    fn existence_proof(key: &[u8], value: &[u8], spec: ics23::ProofSpec) -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(Proof::Exist(ExistenceProof {
+                key: key.to_vec(),
+                value: value.to_vec(),
+                leaf: spec.leaf_spec,
+                path: vec![InnerOp {
+                    hash: HashOp::Sha256.into(),
+                    prefix: vec![0x02, 0x04, 0x20],
+                    suffix: vec![0xab; 32],
+                }],
+            })),
+        }
+    }
+
+    #[test]
+    fn merkle_proof_from_proof_bytes() {
+        // a store proof (iavl) followed by the multistore proof (tendermint),
+        // as returned for a `store/ibc/key` ABCI query
+        let proof = MerkleProof {
+            proofs: vec![
+                existence_proof(
+                    b"nextSequenceRecv/ports/transfer/channels/channel-0",
+                    &[0, 0, 0, 0, 0, 0, 0, 1],
+                    ics23::iavl_spec(),
+                ),
+                existence_proof(b"ibc", &[0xcd; 32], ics23::tendermint_spec()),
+            ],
+        };
+
+        let bytes = CommitmentProofBytes::try_from(proof.clone()).unwrap();
+        assert_eq!(MerkleProof::try_from(bytes).unwrap(), proof);
+    }
+
+    #[test]
+    fn merkle_proof_from_malformed_proof_bytes() {
+        let bytes = CommitmentProofBytes::try_from(vec![0xff, 0xff, 0xff]).unwrap();
+        let err = MerkleProof::try_from(bytes).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidRawMerkleProof(_)
+        ));
+
+        let bytes = CommitmentProofBytes::try_from(get_dummy_merkle_proof()).unwrap();
+        let err = MerkleProof::try_from(bytes).unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::InvalidMerkleProof(_)));
+    }
+
+    #[test]
+    fn commitment_prefix_rejects_empty() {
+        let err = CommitmentPrefix::new(vec![]).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::EmptyCommitmentPrefix(_)
+        ));
+        assert!(CommitmentPrefix::try_from(vec![]).is_err());
+
+        let prefix = CommitmentPrefix::new(b"store".to_vec()).unwrap();
+        assert_eq!(prefix.as_bytes(), b"store");
+    }
+
+    #[test]
+    fn commitment_prefix_cosmos_default() {
+        let prefix = CommitmentPrefix::cosmos_default();
+        assert_eq!(prefix.as_bytes(), b"ibc");
+        assert_eq!(format!("{prefix:?}"), "ibc");
+        assert_eq!(CommitmentPrefix::new(b"ibc".to_vec()).unwrap(), prefix);
+    }
+}
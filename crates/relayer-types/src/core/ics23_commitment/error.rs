@@ -12,6 +12,10 @@ define_error! {
             [ TraceError<DecodeError> ]
             |_| { "failed to decode commitment proof" },
 
+        InvalidHexCommitmentRoot
+            { hex: String }
+            |e| { format_args!("invalid hex-encoded commitment root: {}", e.hex) },
+
         EmptyCommitmentPrefix
             |_| { "empty commitment prefix" },
 
@@ -34,6 +38,17 @@ define_error! {
             |_| { "invalid merkle proof" },
 
         VerificationFailure
-            |_| { "proof verification failed" }
+            |_| { "proof verification failed" },
+
+        UnexpectedExistenceProof
+            { key: String }
+            |e| { format_args!("expected a non-membership proof for key {}, but got a proof of its presence", e.key) },
+
+        RootMismatch
+            { expected: String, got: String }
+            |e| {
+                format_args!("proof root mismatch: expected {}, got {}",
+                    e.expected, e.got)
+            },
     }
 }
@@ -1,3 +1,4 @@
+use subtle_encoding::{Encoding, Hex};
 use tendermint::merkle::proof::ProofOps as TendermintProof;
 
 use ibc_proto::ibc::core::commitment::v1::MerklePath;
@@ -58,6 +59,27 @@ impl MerkleProof {
         value: Vec<u8>,
         start_index: usize,
     ) -> Result<(), Error> {
+        if value.is_empty() {
+            return Err(Error::empty_verified_value());
+        }
+
+        let proven = self.query_membership(specs, root, keys, start_index)?;
+        if proven != value {
+            return Err(Error::verification_failure());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the membership proof against `root` and returns the value
+    /// it proves to be stored under `keys`.
+    pub fn query_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        start_index: usize,
+    ) -> Result<Vec<u8>, Error> {
         // validate arguments
         if self.proofs.is_empty() {
             return Err(Error::empty_merkle_proof());
@@ -73,12 +95,18 @@ impl MerkleProof {
         if keys.key_path.len() != num {
             return Err(Error::number_of_keys_mismatch());
         }
-        if value.is_empty() {
+
+        // the proven value is the one stored in the innermost existence proof
+        let proven = match self.proofs.get(start_index).and_then(|p| p.proof.as_ref()) {
+            Some(Proof::Exist(existence_proof)) => existence_proof.value.clone(),
+            _ => return Err(Error::invalid_merkle_proof()),
+        };
+        if proven.is_empty() {
             return Err(Error::empty_verified_value());
         }
 
-        let mut subroot = value.clone();
-        let mut value = value;
+        let mut subroot = proven.clone();
+        let mut value = proven.clone();
         // keys are represented from root-to-leaf
         for ((proof, spec), key) in self
             .proofs
@@ -109,10 +137,14 @@ impl MerkleProof {
         }
 
         if root.hash != subroot {
-            return Err(Error::verification_failure());
+            let hex = Hex::upper_case();
+            return Err(Error::root_mismatch(
+                hex.encode_to_string(&root.hash).unwrap_or_default(),
+                hex.encode_to_string(&subroot).unwrap_or_default(),
+            ));
         }
 
-        Ok(())
+        Ok(proven)
     }
 
     pub fn verify_non_membership(
@@ -166,6 +198,7 @@ impl MerkleProof {
                 // verify membership proofs starting from index 1 with value = subroot
                 self.verify_membership(specs, root, keys, subroot, 1)
             }
+            Some(Proof::Exist(_)) => Err(Error::unexpected_existence_proof(key.clone())),
             _ => Err(Error::invalid_merkle_proof()),
         }
     }
@@ -250,3 +283,238 @@ pub fn convert_tm_to_ics_merkle_proof(tm_proof: &TendermintProof) -> Result<Merk
 
     Ok(MerkleProof::from(RawMerkleProof { proofs }))
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleRoot};
+    use ics23::commitment_proof::Proof;
+    use ics23::{calculate_existence_root, CommitmentProof, ExistenceProof, NonExistenceProof};
+    use subtle_encoding::{Encoding, Hex};
+
+    use crate::core::ics23_commitment::error::ErrorDetail;
+    use crate::core::ics23_commitment::merkle::MerkleProof;
+    use crate::core::ics23_commitment::specs::ProofSpecs;
+
+    fn dummy_existence_proof(key: &[u8], value: &[u8]) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: ics23::tendermint_spec().leaf_spec,
+            path: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_membership_root_mismatch() {
+        let existence_proof = dummy_existence_proof(b"key", b"value");
+        let computed_root =
+            calculate_existence_root::<ics23::HostFunctionsManager>(&existence_proof).unwrap();
+        let computed_root_hex = Hex::upper_case().encode_to_string(&computed_root).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+        let specs = ProofSpecs::from(vec![ics23::tendermint_spec()]);
+        let keys = MerklePath {
+            key_path: vec!["key".to_string()],
+        };
+
+        proof
+            .verify_membership(
+                &specs,
+                MerkleRoot {
+                    hash: computed_root.clone(),
+                },
+                keys.clone(),
+                b"value".to_vec(),
+                0,
+            )
+            .unwrap();
+
+        let err = proof
+            .verify_membership(
+                &specs,
+                MerkleRoot {
+                    hash: vec![0xab; 32],
+                },
+                keys,
+                b"value".to_vec(),
+                0,
+            )
+            .unwrap_err();
+
+        match err.detail() {
+            ErrorDetail::RootMismatch(e) => {
+                assert_eq!(e.expected, "AB".repeat(32));
+                assert_eq!(e.got, computed_root_hex);
+            }
+            _ => panic!("expected a root mismatch, got {err}"),
+        }
+
+        let message = err.to_string();
+        assert!(message.contains(&"AB".repeat(32)));
+        assert!(message.contains(&computed_root_hex));
+    }
+
+    #[test]
+    fn query_membership_returns_proven_value() {
+        let existence_proof = dummy_existence_proof(b"nextSequenceRecv", &[0, 0, 0, 0, 0, 0, 0, 7]);
+        let root =
+            calculate_existence_root::<ics23::HostFunctionsManager>(&existence_proof).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![CommitmentProof {
+                proof: Some(Proof::Exist(existence_proof)),
+            }],
+        };
+        let specs = ProofSpecs::from(vec![ics23::tendermint_spec()]);
+        let keys = MerklePath {
+            key_path: vec!["nextSequenceRecv".to_string()],
+        };
+
+        let value = proof
+            .query_membership(&specs, MerkleRoot { hash: root.clone() }, keys.clone(), 0)
+            .unwrap();
+        assert_eq!(value, vec![0, 0, 0, 0, 0, 0, 0, 7]);
+
+        let err = proof
+            .verify_membership(
+                &specs,
+                MerkleRoot { hash: root },
+                keys,
+                vec![0, 0, 0, 0, 0, 0, 0, 8],
+                0,
+            )
+            .unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::VerificationFailure(_)));
+    }
+
+    #[test]
+    fn verify_membership_with_cosmos_specs() {
+        // the iavl store proof: the leaf prefix carries the zigzag varint-encoded
+        // height (0, the spec prefix), size (1) and version (1) of the node
+        let mut iavl_leaf = ics23::iavl_spec().leaf_spec.unwrap();
+        iavl_leaf.prefix.extend([0x02, 0x02]);
+        let store_proof = ExistenceProof {
+            key: b"key".to_vec(),
+            value: b"value".to_vec(),
+            leaf: Some(iavl_leaf),
+            path: vec![],
+        };
+        let store_root =
+            calculate_existence_root::<ics23::HostFunctionsManager>(&store_proof).unwrap();
+
+        // the multistore proof, committing to the store root under the store name
+        let multistore_proof = dummy_existence_proof(b"ibc", &store_root);
+        let app_hash =
+            calculate_existence_root::<ics23::HostFunctionsManager>(&multistore_proof).unwrap();
+
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store_proof)),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(multistore_proof)),
+                },
+            ],
+        };
+        let keys = MerklePath {
+            key_path: vec!["ibc".to_string(), "key".to_string()],
+        };
+
+        proof
+            .verify_membership(
+                &ProofSpecs::cosmos(),
+                MerkleRoot {
+                    hash: app_hash.clone(),
+                },
+                keys.clone(),
+                b"value".to_vec(),
+                0,
+            )
+            .unwrap();
+
+        let err = proof
+            .verify_membership(
+                &ProofSpecs::from(vec![ics23::tendermint_spec()]),
+                MerkleRoot { hash: app_hash },
+                keys,
+                b"value".to_vec(),
+                0,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::NumberOfSpecsMismatch(_)
+        ));
+    }
+
+    #[test]
+    fn verify_non_membership_absence_and_presence() {
+        let tendermint_specs =
+            ProofSpecs::from(vec![ics23::tendermint_spec(), ics23::tendermint_spec()]);
+
+        // the store holds a single key `a`, so `b` is absent: its left
+        // neighbour is `a` and it has no right neighbour
+        let left = dummy_existence_proof(b"a", b"value");
+        let store_root = calculate_existence_root::<ics23::HostFunctionsManager>(&left).unwrap();
+        let multistore_proof = dummy_existence_proof(b"ibc", &store_root);
+        let app_hash =
+            calculate_existence_root::<ics23::HostFunctionsManager>(&multistore_proof).unwrap();
+        let multistore_proof = CommitmentProof {
+            proof: Some(Proof::Exist(multistore_proof)),
+        };
+
+        let absence = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Nonexist(NonExistenceProof {
+                        key: b"b".to_vec(),
+                        left: Some(left.clone()),
+                        right: None,
+                    })),
+                },
+                multistore_proof.clone(),
+            ],
+        };
+        absence
+            .verify_non_membership(
+                &tendermint_specs,
+                MerkleRoot {
+                    hash: app_hash.clone(),
+                },
+                MerklePath {
+                    key_path: vec!["ibc".to_string(), "b".to_string()],
+                },
+            )
+            .unwrap();
+
+        let presence = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(left)),
+                },
+                multistore_proof,
+            ],
+        };
+        let err = presence
+            .verify_non_membership(
+                &tendermint_specs,
+                MerkleRoot { hash: app_hash },
+                MerklePath {
+                    key_path: vec!["ibc".to_string(), "a".to_string()],
+                },
+            )
+            .unwrap_err();
+
+        match err.detail() {
+            ErrorDetail::UnexpectedExistenceProof(e) => assert_eq!(e.key, "a"),
+            _ => panic!("expected an unexpected existence proof error, got {err}"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! ICS 24: Host defines the minimal set of interfaces that a
 //! state machine hosting an IBC-enabled chain must implement.
 
-pub use path::{ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH};
+pub use path::{ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_PATH, SDK_UPGRADE_QUERY_PATH};
 
 pub mod error;
 pub mod identifier;
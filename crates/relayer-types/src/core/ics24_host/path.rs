@@ -17,6 +17,11 @@ pub const IBC_QUERY_PATH: &str = "store/ibc/key";
 /// ## Note: This is SDK/Tendermint specific!
 pub const SDK_UPGRADE_QUERY_PATH: &str = "store/upgrade/key";
 
+/// Default upgrade path of a client state, i.e. the keys under which the
+/// upgraded IBC state is stored within the upgrade sub-store
+/// ## Note: This is SDK/Tendermint specific!
+pub const SDK_UPGRADE_PATH: [&str; 2] = ["upgrade", UPGRADED_IBC_STATE];
+
 /// ABCI client upgrade keys
 /// - The key identifying the upgraded IBC state within the upgrade sub-store
 const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
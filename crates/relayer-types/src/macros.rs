@@ -47,3 +47,55 @@ macro_rules! downcast {
         None
     }
 }
+
+/// Downcast the given arguments to the associated enum variant, by reference.
+///
+/// Unlike [`downcast!`], this borrows its arguments instead of consuming them,
+/// and yields references to the inner values.
+///
+/// ## Note
+/// Only works for enums whose variants only hold a single value.
+///
+/// ## Example
+///
+/// ```rust
+/// use ibc_relayer_types::downcast_ref;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Foo {
+///     Bar(String),
+///     Baz(bool),
+/// }
+///
+/// let bar = Foo::Bar("bar".to_string());
+/// let baz = Foo::Baz(true);
+///
+/// assert_eq!(downcast_ref!(bar => Foo::Bar), Some(&"bar".to_string()));
+/// assert_eq!(downcast_ref!(bar => Foo::Baz), None);
+/// assert_eq!(
+///     downcast_ref!(bar => Foo::Bar, baz => Foo::Baz),
+///     Some((&"bar".to_string(), &true))
+/// );
+///
+/// // The original values are still usable.
+/// assert_eq!(bar, Foo::Bar("bar".to_string()));
+/// assert_eq!(baz, Foo::Baz(true));
+/// ```
+#[macro_export]
+macro_rules! downcast_ref {
+    ( $e1:expr => $p1:path, $( $e:expr => $p:path ),+ $(,)? ) => {
+        downcast_ref!($e1 => $p1).zip(downcast_ref!($($e => $p),+))
+    };
+
+    ($e:expr => $p:path) => {
+        match &$e {
+            $p(e) => Some(e),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+
+    () => {
+        None
+    }
+}
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error as _};
 use serde::ser::{Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 
@@ -10,6 +11,16 @@ where
     hex.serialize(serializer)
 }
 
+pub fn deser_hex_upper<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?;
+    Hex::upper_case()
+        .decode(hex.to_uppercase())
+        .map_err(D::Error::custom)
+}
+
 pub mod serde_string {
 
     use core::fmt::Display;
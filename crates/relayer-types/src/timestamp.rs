@@ -129,6 +129,27 @@ impl Timestamp {
         self.time
     }
 
+    /// Adds the given duration to this timestamp, saturating at the largest
+    /// timestamp representable in the IBC protocol (`u64::MAX` nanoseconds
+    /// since the Unix epoch) instead of failing on overflow.
+    ///
+    /// Unlike the `+` operator, which reports an overflow as an error, this
+    /// is meant for expiry and timeout computations where "never" is an
+    /// acceptable outcome. A timestamp that is not set stays unset.
+    pub fn saturating_add(self, duration: Duration) -> Timestamp {
+        if self.time.is_none() {
+            return self;
+        }
+
+        let max = Timestamp::from_nanoseconds(u64::MAX)
+            .expect("u64::MAX nanoseconds is a valid timestamp");
+
+        match self + duration {
+            Ok(timestamp) if timestamp <= max => timestamp,
+            _ => max,
+        }
+    }
+
     /// Checks whether the timestamp has expired when compared to the
     /// `other` timestamp. Returns an [`Expiry`] result.
     pub fn check_expiry(&self, other: &Timestamp) -> Expiry {
@@ -293,6 +314,27 @@ mod tests {
         assert_eq!(time0, (time0 - duration).unwrap());
     }
 
+    #[test]
+    fn test_timestamp_saturating_add() {
+        let time = Timestamp::from_nanoseconds(100).unwrap();
+        let duration = Duration::from_nanos(50);
+        assert_eq!(
+            time.saturating_add(duration),
+            Timestamp::from_nanoseconds(150).unwrap()
+        );
+
+        let near_max = Timestamp::from_nanoseconds(u64::MAX - 10).unwrap();
+        let max = Timestamp::from_nanoseconds(u64::MAX).unwrap();
+        assert_eq!(near_max.saturating_add(duration), max);
+        assert_eq!(near_max.saturating_add(Duration::MAX), max);
+        assert_eq!(near_max.saturating_add(duration).nanoseconds(), u64::MAX);
+
+        assert_eq!(
+            Timestamp::none().saturating_add(duration),
+            Timestamp::none()
+        );
+    }
+
     #[test]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
@@ -46,7 +46,7 @@ use ibc_relayer_types::core::ics24_host::path::{
     SeqRecvsPath,
 };
 use ibc_relayer_types::core::ics24_host::{
-    ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
+    ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_PATH, SDK_UPGRADE_QUERY_PATH,
 };
 use ibc_relayer_types::core::{
     ics02_client::height::Height, ics04_channel::upgrade::ErrorReceipt,
@@ -1229,7 +1229,7 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_commitment_prefix");
 
         // TODO - do a real chain query
-        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
+        CommitmentPrefix::new(self.config.store_prefix.as_bytes().to_vec())
             .map_err(|_| Error::ics02(ClientError::empty_prefix()))
     }
 
@@ -2321,7 +2321,7 @@ impl ChainEndpoint for CosmosSdkChain {
             settings.max_clock_drift,
             height,
             proof_specs,
-            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            SDK_UPGRADE_PATH.map(String::from).into(),
             AllowUpdate {
                 after_expiry: true,
                 after_misbehaviour: true,
@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+use std::fmt::{Display, Error as FmtError, Formatter};
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
@@ -15,8 +17,24 @@ use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::error::ValidationError;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
 
+use crate::consensus_state::AnyConsensusState;
+
+/// Status of a client from the point of view of a relayer, see [`AnyClientState::status`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClientStatus {
+    /// The client is up to date with the counterparty chain.
+    Active,
+    /// The client is usable but its latest height is behind the counterparty chain.
+    Behind,
+    /// The latest consensus state of the client is outside of the trusting period.
+    Expired,
+    /// The client is frozen following misbehaviour.
+    Frozen,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AnyClientState {
@@ -71,6 +89,79 @@ impl AnyClientState {
             Self::Tendermint(state) => state.expired(elapsed),
         }
     }
+
+    /// Compares two client states by their latest height only.
+    ///
+    /// This ignores the chain id and every other field, so it is meant for
+    /// ordering client states of the same chain (e.g. with `sort_by`), not as
+    /// a general notion of ordering between client states.
+    pub fn cmp_by_height(&self, other: &Self) -> Ordering {
+        self.latest_height().cmp(&other.latest_height())
+    }
+
+    /// Determines the status of the client, given the timestamp of its consensus
+    /// state at the latest height, the current height of the counterparty chain
+    /// and the current time. A frozen client takes precedence over an expired one,
+    /// which takes precedence over one that is merely behind.
+    pub fn status(
+        &self,
+        consensus_state_timestamp: Timestamp,
+        counterparty_height: Height,
+        now: Timestamp,
+    ) -> ClientStatus {
+        if self.frozen_height().is_some() {
+            return ClientStatus::Frozen;
+        }
+
+        let elapsed = now
+            .duration_since(&consensus_state_timestamp)
+            .unwrap_or_default();
+
+        if self.expired(elapsed) {
+            ClientStatus::Expired
+        } else if self.latest_height() < counterparty_height {
+            ClientStatus::Behind
+        } else {
+            ClientStatus::Active
+        }
+    }
+
+    /// Compares two client states field by field, except for their frozen height.
+    ///
+    /// Use this instead of `PartialEq` when comparing a cached client state with
+    /// one freshly queried from chain, where the frozen height may transiently
+    /// differ. Use `PartialEq` when the frozen status matters, e.g. when deciding
+    /// whether the client can still be updated.
+    pub fn matches_ignoring_frozen(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Tendermint(this), Self::Tendermint(other)) => {
+                TmClientState {
+                    frozen_height: None,
+                    ..this.clone()
+                } == TmClientState {
+                    frozen_height: None,
+                    ..other.clone()
+                }
+            }
+        }
+    }
+}
+
+impl Display for AnyClientState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ClientState {{ type: {}, chain_id: {}, latest_height: {}, frozen_height: ",
+            self.client_type().as_str(),
+            self.chain_id(),
+            self.latest_height()
+        )?;
+
+        match self.frozen_height() {
+            Some(frozen_height) => write!(f, "{frozen_height} }}"),
+            None => write!(f, "None }}"),
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -84,7 +175,7 @@ impl TryFrom<Any> for AnyClientState {
 
             TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Tendermint(
                 Protobuf::<RawTmClientState>::decode_vec(&raw.value)
-                    .map_err(Error::decode_raw_client_state)?,
+                    .map_err(|e| Error::decode_raw_client_state(raw.type_url.clone(), e))?,
             )),
 
             _ => Err(Error::unknown_client_state_type(raw.type_url)),
@@ -173,3 +264,300 @@ impl From<IdentifiedAnyClientState> for IdentifiedClientState {
         }
     }
 }
+
+/// A snapshot of a client, bundling its client state with the consensus
+/// state at the client's latest height, suitable for persisting to disk.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientRecord {
+    pub client_id: ClientId,
+    pub client_type: ClientType,
+    pub client_state: AnyClientState,
+    pub consensus_state: AnyConsensusState,
+}
+
+impl ClientRecord {
+    pub fn new(
+        client_id: ClientId,
+        client_state: AnyClientState,
+        consensus_state: AnyConsensusState,
+    ) -> Self {
+        Self {
+            client_id,
+            client_type: client_state.client_type(),
+            client_state,
+            consensus_state,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use super::*;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::AllowUpdate;
+    use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+    use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+    use tendermint::{Hash, Time};
+
+    pub fn dummy_client_state(chain_id: &ChainId, height: u64) -> AnyClientState {
+        AnyClientState::from(
+            TmClientState::new(
+                chain_id.clone(),
+                TrustThreshold::default(),
+                Duration::from_secs(64000),
+                Duration::from_secs(128000),
+                Duration::from_millis(3000),
+                Height::new(chain_id.version(), height).unwrap(),
+                ProofSpecs::default(),
+                vec![],
+                AllowUpdate {
+                    after_expiry: false,
+                    after_misbehaviour: false,
+                },
+            )
+            .unwrap(),
+        )
+    }
+
+    pub fn dummy_consensus_state() -> AnyConsensusState {
+        AnyConsensusState::from(TmConsensusState::new(
+            CommitmentRoot::from_bytes(&[0x01, 0x02, 0xab, 0xcd]),
+            Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+            Hash::Sha256([7; 32]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_util::{dummy_client_state, dummy_consensus_state};
+    use super::*;
+
+    use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawTmConsensusState;
+    use ibc_relayer_types::clients::ics07_tendermint::consensus_state::TENDERMINT_CONSENSUS_STATE_TYPE_URL;
+    use ibc_relayer_types::core::ics02_client::error::ErrorDetail;
+
+    #[test]
+    fn chain_id_revision_matches_latest_height() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+
+        assert_eq!(client_state.chain_id(), chain_id);
+        assert_eq!(
+            client_state.chain_id().version(),
+            client_state.latest_height().revision_number()
+        );
+    }
+
+    #[test]
+    fn any_client_state_encode_to_any_delegates() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+        let AnyClientState::Tendermint(tm_client_state) = &client_state;
+
+        let any = client_state.encode_to_any();
+        assert_eq!(any, tm_client_state.encode_to_any());
+        assert_eq!(any.type_url, TENDERMINT_CLIENT_STATE_TYPE_URL);
+    }
+
+    #[test]
+    fn lift_tendermint_states_into_any() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let AnyClientState::Tendermint(tm_client_state) = dummy_client_state(&chain_id, 42);
+
+        let client_state: AnyClientState = tm_client_state.clone().into();
+        assert!(matches!(client_state, AnyClientState::Tendermint(cs) if cs == tm_client_state));
+
+        let AnyConsensusState::Tendermint(tm_consensus_state) = dummy_consensus_state();
+        let consensus_state: AnyConsensusState = tm_consensus_state.clone().into();
+        assert!(
+            matches!(consensus_state, AnyConsensusState::Tendermint(cs) if cs == tm_consensus_state)
+        );
+    }
+
+    #[test]
+    fn client_record_json_roundtrip() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let record = ClientRecord::new(
+            "07-tendermint-3".parse().unwrap(),
+            dummy_client_state(&chain_id, 42),
+            dummy_consensus_state(),
+        );
+        assert_eq!(record.client_type, ClientType::Tendermint);
+
+        let json = serde_json::to_string_pretty(&record).unwrap();
+        assert!(json.contains("\"client_id\": \"07-tendermint-3\""));
+
+        let reloaded: ClientRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, record);
+    }
+
+    #[test]
+    fn client_states_match_ignoring_frozen_height() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+        let AnyClientState::Tendermint(tm_client_state) = client_state.clone();
+        let frozen = AnyClientState::from(
+            tm_client_state
+                .with_frozen_height(Height::new(7, 40).unwrap())
+                .unwrap(),
+        );
+
+        assert_ne!(client_state, frozen);
+        assert!(client_state.matches_ignoring_frozen(&frozen));
+        assert!(frozen.matches_ignoring_frozen(&client_state));
+
+        let other_height = dummy_client_state(&chain_id, 43);
+        assert!(!client_state.matches_ignoring_frozen(&other_height));
+    }
+
+    #[test]
+    fn any_client_state_encode_any_vec_is_deterministic() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+        let AnyClientState::Tendermint(tm_client_state) = &client_state;
+
+        let bytes = client_state.encode_any_vec();
+        assert_eq!(bytes, client_state.clone().encode_any_vec());
+        assert_eq!(bytes, tm_client_state.encode_any_vec());
+        assert_eq!(bytes, Protobuf::<Any>::encode_vec(client_state));
+    }
+
+    #[test]
+    fn client_status() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+        let AnyClientState::Tendermint(tm_client_state) = client_state.clone();
+        let frozen = AnyClientState::from(
+            tm_client_state
+                .with_frozen_height(Height::new(7, 40).unwrap())
+                .unwrap(),
+        );
+
+        let consensus_state_timestamp = Timestamp::from_nanoseconds(1_000_000_000_000).unwrap();
+        // the dummy client state has a trusting period of 64000s
+        let within_trusting_period =
+            (consensus_state_timestamp + Duration::from_secs(100)).unwrap();
+        let after_trusting_period =
+            (consensus_state_timestamp + Duration::from_secs(64001)).unwrap();
+
+        let same_height = Height::new(7, 42).unwrap();
+        let higher_height = Height::new(7, 50).unwrap();
+
+        assert_eq!(
+            client_state.status(
+                consensus_state_timestamp,
+                same_height,
+                within_trusting_period
+            ),
+            ClientStatus::Active
+        );
+        assert_eq!(
+            client_state.status(
+                consensus_state_timestamp,
+                higher_height,
+                within_trusting_period
+            ),
+            ClientStatus::Behind
+        );
+        assert_eq!(
+            client_state.status(
+                consensus_state_timestamp,
+                higher_height,
+                after_trusting_period
+            ),
+            ClientStatus::Expired
+        );
+        assert_eq!(
+            frozen.status(
+                consensus_state_timestamp,
+                higher_height,
+                after_trusting_period
+            ),
+            ClientStatus::Frozen
+        );
+    }
+
+    #[test]
+    fn sort_client_states_by_height() {
+        let chain_a = ChainId::new("chain-a".to_string(), 1);
+        let chain_b = ChainId::new("chain-b".to_string(), 1);
+
+        let mut client_states = [
+            dummy_client_state(&chain_a, 30),
+            dummy_client_state(&chain_b, 10),
+            dummy_client_state(&chain_a, 20),
+        ];
+        client_states.sort_by(AnyClientState::cmp_by_height);
+
+        let heights: Vec<u64> = client_states
+            .iter()
+            .map(|cs| cs.latest_height().revision_height())
+            .collect();
+        assert_eq!(heights, [10, 20, 30]);
+
+        assert_eq!(
+            client_states[0].cmp_by_height(&dummy_client_state(&chain_a, 10)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn any_client_state_display() {
+        let chain_id = ChainId::new("ibc".to_string(), 7);
+        let client_state = dummy_client_state(&chain_id, 42);
+
+        assert_eq!(
+            client_state.to_string(),
+            "ClientState { type: 07-tendermint, chain_id: ibc-7, latest_height: 7-42, frozen_height: None }"
+        );
+
+        let AnyClientState::Tendermint(tm_state) = client_state;
+        let frozen = AnyClientState::from(
+            tm_state
+                .with_frozen_height(Height::new(7, 40).unwrap())
+                .unwrap(),
+        );
+
+        assert_eq!(
+            frozen.to_string(),
+            "ClientState { type: 07-tendermint, chain_id: ibc-7, latest_height: 7-42, frozen_height: 7-40 }"
+        );
+    }
+
+    #[test]
+    fn any_client_state_rejects_consensus_state_type_url() {
+        let AnyConsensusState::Tendermint(consensus_state) = dummy_consensus_state();
+        let mislabeled = Any {
+            type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawTmConsensusState>::encode_vec(consensus_state),
+        };
+
+        match AnyClientState::try_from(mislabeled).map_err(|e| e.into_detail()) {
+            Err(ErrorDetail::UnknownClientStateType(e)) => {
+                assert_eq!(e.client_state_type, TENDERMINT_CONSENSUS_STATE_TYPE_URL)
+            }
+            res => panic!("expected an unknown client state type error, got {res:?}"),
+        }
+
+        let client_state = dummy_client_state(&ChainId::new("ibc".to_string(), 7), 42);
+        let decoded = AnyClientState::try_from(Any::from(client_state.clone())).unwrap();
+        assert_eq!(decoded, client_state);
+    }
+
+    #[test]
+    fn any_client_state_decode_error_names_type_url() {
+        let garbage = Any {
+            type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: vec![0xff; 4],
+        };
+
+        let err = AnyClientState::try_from(garbage).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::DecodeRawClientState(e) if e.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL
+        ));
+        assert!(err.to_string().contains(TENDERMINT_CLIENT_STATE_TYPE_URL));
+    }
+}
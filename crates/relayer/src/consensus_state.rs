@@ -1,3 +1,5 @@
+use std::fmt::{Display, Error as FmtError, Formatter};
+
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
@@ -34,6 +36,17 @@ impl AnyConsensusState {
     }
 }
 
+impl Display for AnyConsensusState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ConsensusState {{ type: {}, timestamp: {} }}",
+            self.client_type().as_str(),
+            self.timestamp()
+        )
+    }
+}
+
 impl Protobuf<Any> for AnyConsensusState {}
 
 impl TryFrom<Any> for AnyConsensusState {
@@ -45,7 +58,7 @@ impl TryFrom<Any> for AnyConsensusState {
 
             TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Tendermint(
                 Protobuf::<RawConsensusState>::decode_vec(&value.value)
-                    .map_err(Error::decode_raw_client_state)?,
+                    .map_err(|e| Error::invalid_raw_consensus_state(value.type_url.clone(), e))?,
             )),
 
             _ => Err(Error::unknown_consensus_state_type(value.type_url)),
@@ -76,6 +89,12 @@ pub struct AnyConsensusStateWithHeight {
     pub consensus_state: AnyConsensusState,
 }
 
+impl Display for AnyConsensusStateWithHeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} at height {}", self.consensus_state, self.height)
+    }
+}
+
 impl Protobuf<ConsensusStateWithHeight> for AnyConsensusStateWithHeight {}
 
 impl TryFrom<ConsensusStateWithHeight> for AnyConsensusStateWithHeight {
@@ -122,3 +141,81 @@ impl ConsensusState for AnyConsensusState {
         AnyConsensusState::timestamp(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::client_state::test_util::dummy_consensus_state;
+    use ibc_relayer_types::core::ics02_client::error::ErrorDetail;
+
+    #[test]
+    fn any_consensus_state_json_roundtrip() {
+        let consensus_state = dummy_consensus_state();
+
+        let json = serde_json::to_string(&consensus_state).unwrap();
+        assert!(json.contains("\"type\":\"Tendermint\""));
+        assert!(json.contains("\"root\":\"0102ABCD\""));
+
+        let decoded: AnyConsensusState = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, consensus_state);
+    }
+
+    #[test]
+    fn any_consensus_state_display() {
+        let consensus_state = dummy_consensus_state();
+
+        assert_eq!(
+            consensus_state.to_string(),
+            "ConsensusState { type: 07-tendermint, timestamp: 2023-11-14T22:13:20Z }"
+        );
+
+        let with_height = AnyConsensusStateWithHeight {
+            height: Height::new(1, 42).unwrap(),
+            consensus_state,
+        };
+
+        assert_eq!(
+            with_height.to_string(),
+            "ConsensusState { type: 07-tendermint, timestamp: 2023-11-14T22:13:20Z } at height 1-42"
+        );
+    }
+
+    #[test]
+    fn any_consensus_state_encode_to_any_delegates() {
+        let consensus_state = dummy_consensus_state();
+        let AnyConsensusState::Tendermint(tm_consensus_state) = &consensus_state;
+
+        let any = consensus_state.encode_to_any();
+        assert_eq!(any, tm_consensus_state.encode_to_any());
+        assert_eq!(any.type_url, TENDERMINT_CONSENSUS_STATE_TYPE_URL);
+    }
+
+    #[test]
+    fn any_consensus_state_decode_error_names_type_url() {
+        let garbage = Any {
+            type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: vec![0xff; 4],
+        };
+
+        let err = AnyConsensusState::try_from(garbage).unwrap_err();
+        assert!(matches!(
+            err.detail(),
+            ErrorDetail::InvalidRawConsensusState(e) if e.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL
+        ));
+        assert!(err
+            .to_string()
+            .contains(TENDERMINT_CONSENSUS_STATE_TYPE_URL));
+    }
+
+    #[test]
+    fn any_consensus_state_encode_any_vec_is_deterministic() {
+        let consensus_state = dummy_consensus_state();
+        let AnyConsensusState::Tendermint(tm_consensus_state) = &consensus_state;
+
+        let bytes = consensus_state.encode_any_vec();
+        assert_eq!(bytes, consensus_state.clone().encode_any_vec());
+        assert_eq!(bytes, tm_consensus_state.encode_any_vec());
+        assert_eq!(bytes, Protobuf::<Any>::encode_vec(consensus_state));
+    }
+}
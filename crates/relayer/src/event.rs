@@ -662,6 +662,68 @@ pub fn parse_timeout_height(s: &str) -> Result<TimeoutHeight, ChannelError> {
 mod tests {
     use super::*;
 
+    use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+
+    #[test]
+    fn client_event_to_abci_event() {
+        let attributes = ClientAttributes {
+            client_id: "07-tendermint-3".parse().unwrap(),
+            client_type: ClientType::Tendermint,
+            consensus_height: Height::new(1, 42).unwrap(),
+        };
+        let mut abci_events = vec![];
+        let create_client = client_events::CreateClient::from(attributes.clone());
+        abci_events.push(AbciEvent::from(create_client.clone()));
+        let update_client = client_events::UpdateClient::from(attributes.clone());
+        abci_events.push(AbciEvent::from(update_client.clone()));
+        let upgrade_client = client_events::UpgradeClient::from(attributes.clone());
+        abci_events.push(AbciEvent::from(upgrade_client.clone()));
+        let misbehaviour = client_events::ClientMisbehaviour::from(attributes);
+        abci_events.push(AbciEvent::from(misbehaviour.clone()));
+
+        for abci_event in abci_events {
+            match ibc_event_try_from_abci_event(&abci_event).ok() {
+                Some(ibc_event) => match ibc_event {
+                    IbcEvent::CreateClient(e) => assert_eq!(e, create_client),
+                    IbcEvent::UpdateClient(e) => assert_eq!(e, update_client),
+                    IbcEvent::UpgradeClient(e) => assert_eq!(e, upgrade_client),
+                    IbcEvent::ClientMisbehaviour(e) => assert_eq!(e, misbehaviour),
+                    _ => panic!("unexpected event type"),
+                },
+                None => panic!("converted event was wrong"),
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_client_abci_event() {
+        let malformed = [
+            AbciEvent::new(
+                "create_client",
+                [(client_events::CONSENSUS_HEIGHT_ATTRIBUTE_KEY, "forty-two")],
+            ),
+            AbciEvent::new(
+                "update_client",
+                [(client_events::CLIENT_TYPE_ATTRIBUTE_KEY, "99-unknown")],
+            ),
+            AbciEvent::new(
+                "create_client",
+                [(client_events::CLIENT_ID_ATTRIBUTE_KEY, "")],
+            ),
+            AbciEvent::new(
+                "unknown_client_event",
+                [(client_events::CLIENT_ID_ATTRIBUTE_KEY, "07-tendermint-3")],
+            ),
+        ];
+
+        for abci_event in malformed {
+            assert!(
+                ibc_event_try_from_abci_event(&abci_event).is_err(),
+                "parsing should fail for {abci_event:?}"
+            );
+        }
+    }
+
     #[test]
     fn connection_event_to_abci_event() {
         let attributes = ConnectionAttributes {
@@ -14,7 +14,6 @@ use tracing::{debug, error, info, instrument, trace, warn};
 
 use flex_error::define_error;
 use ibc_relayer_types::applications::ics28_ccv::msgs::ccv_misbehaviour::MsgSubmitIcsConsumerMisbehaviour;
-use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
@@ -24,7 +23,7 @@ use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
 use ibc_relayer_types::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
-use ibc_relayer_types::downcast;
+use ibc_relayer_types::downcast_ref;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
 use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
@@ -51,14 +50,14 @@ const MAX_RETRIES: usize = 5;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ExpiredOrFrozen {
     Expired,
-    Frozen,
+    Frozen { height: Height },
 }
 
 impl fmt::Display for ExpiredOrFrozen {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ExpiredOrFrozen::Expired => write!(f, "expired"),
-            ExpiredOrFrozen::Frozen => write!(f, "frozen"),
+            ExpiredOrFrozen::Frozen { height } => write!(f, "frozen at height {height}"),
         }
     }
 }
@@ -330,7 +329,7 @@ impl HasExpiredOrFrozenError for ForeignClientErrorDetail {
 
     fn is_frozen_error(&self) -> bool {
         if let Self::ExpiredOrFrozen(e) = self {
-            e.status == ExpiredOrFrozen::Frozen
+            matches!(e.status, ExpiredOrFrozen::Frozen { .. })
         } else {
             false
         }
@@ -671,7 +670,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             })?;
 
         //TODO Get acct_prefix
-        let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
+        let msg = MsgCreateClient::from_states(client_state, consensus_state, signer)
             .map_err(ForeignClientError::client)?;
 
         Ok(msg)
@@ -752,9 +751,9 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 })?
         };
 
-        if client_state.is_frozen() {
+        if let Some(height) = client_state.frozen_height() {
             return Err(ForeignClientError::expired_or_frozen(
-                ExpiredOrFrozen::Frozen,
+                ExpiredOrFrozen::Frozen { height },
                 self.id().clone(),
                 self.dst_chain.id(),
                 "client state reports that client is frozen".into(),
@@ -1456,7 +1455,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         // were submitted to chain. However this is not what it's observed during testing.
         // Regardless, just take the event from the first update.
         let event = &events_with_heights[0].event;
-        let update = downcast!(event.clone() => IbcEvent::UpdateClient).ok_or_else(|| {
+        let update = downcast_ref!(event => IbcEvent::UpdateClient).ok_or_else(|| {
             ForeignClientError::unexpected_event(
                 self.id().clone(),
                 self.dst_chain.id(),
@@ -1464,7 +1463,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
-        Ok(Some(update))
+        Ok(Some(update.clone()))
     }
 
     /// Returns the consensus state at `height` or error if not found.
@@ -1924,3 +1923,38 @@ pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientErr
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frozen_client_error_carries_frozen_height() {
+        let frozen_height = Height::new(1, 40).unwrap();
+        let err = ForeignClientError::expired_or_frozen(
+            ExpiredOrFrozen::Frozen {
+                height: frozen_height,
+            },
+            "07-tendermint-0".parse().unwrap(),
+            ChainId::new("ibc".to_string(), 1),
+            "client state reports that client is frozen".into(),
+        );
+
+        assert!(err.is_frozen_error());
+        assert!(!err.is_expired_error());
+
+        match err.detail() {
+            ForeignClientErrorDetail::ExpiredOrFrozen(e) => assert_eq!(
+                e.status,
+                ExpiredOrFrozen::Frozen {
+                    height: frozen_height
+                }
+            ),
+            _ => panic!("expected a frozen client error, got {err}"),
+        }
+
+        assert!(err
+            .to_string()
+            .starts_with("client 07-tendermint-0 on chain id ibc-1 is frozen at height 1-40"));
+    }
+}
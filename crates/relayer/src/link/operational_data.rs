@@ -413,3 +413,48 @@ impl Display for OperationalInfo {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn block_delay_rounds_up_to_whole_blocks() {
+        let block_time = Duration::from_secs(6);
+
+        assert_eq!(calculate_block_delay(Duration::ZERO, block_time), 0);
+        assert_eq!(
+            calculate_block_delay(Duration::from_secs(60), block_time),
+            10
+        );
+        assert_eq!(
+            calculate_block_delay(Duration::from_secs(61), block_time),
+            11
+        );
+        assert_eq!(calculate_block_delay(Duration::from_secs(1), block_time), 1);
+    }
+
+    #[test]
+    fn block_delay_with_zero_block_time() {
+        assert_eq!(
+            calculate_block_delay(Duration::from_secs(60), Duration::ZERO),
+            0
+        );
+    }
+
+    #[test]
+    fn block_delay_remaining_from_update_height() {
+        let mut delay = ConnectionDelay::new(Duration::from_secs(30));
+        delay.update_height = Some(Height::new(0, 100).unwrap());
+
+        let block_delay = delay.conn_block_delay(Duration::from_secs(6));
+        assert_eq!(block_delay, 5);
+
+        let remaining =
+            |h| delay.conn_block_delay_remaining(block_delay, Height::new(0, h).unwrap());
+        assert_eq!(remaining(101), 4);
+        assert_eq!(remaining(105), 0);
+        assert_eq!(remaining(110), 0);
+    }
+}
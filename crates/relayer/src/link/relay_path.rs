@@ -872,6 +872,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
     /// Checks if a packet commitment has been cleared on source.
     /// The packet commitment is cleared when either an acknowledgment or a timeout is received on source.
+    /// If the commitment is still present, also checks that it matches the packet being relayed.
     fn send_packet_commitment_cleared_on_src(&self, packet: &Packet) -> Result<bool, LinkError> {
         let (bytes, _) = self
             .src_chain()
@@ -886,7 +887,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(LinkError::relayer)?;
 
-        Ok(bytes.is_empty())
+        if bytes.is_empty() {
+            return Ok(true);
+        }
+
+        if let Err(e) = packet.verify_commitment(&bytes.into()) {
+            warn!(
+                packet = %packet,
+                "packet commitment on source chain does not match the packet: {e}"
+            );
+        }
+
+        Ok(false)
     }
 
     /// Checks if a send packet event has already been handled (e.g. by another relayer).